@@ -0,0 +1,118 @@
+// Generalized entry point that accepts any address shape this crate understands — plain
+// IPv4, an IPv4 socket address, or bracketed/bare IPv6 (optionally ipv4-in-ipv6) — and any
+// EPID word group of a supported width, dispatching to the right codec via the typed
+// `Address`/`Epid` front door instead of requiring the caller to know which one applies.
+
+use crate::codec::Ordinal;
+use crate::ipv4;
+use crate::ipv6;
+use crate::types::{Address, Epid};
+
+pub fn addr_to_epid(addr: &str) -> Option<String> {
+    match addr.parse::<Address>().ok()? {
+        Address::V4(ip, None) => ipv4::ipv4_to_epid3(&ip.to_string()),
+        Address::V4(ip, Some(port)) => ipv4::socketaddr_to_epid(&format!("{}:{}", ip, port)),
+        Address::V6(ip) => ipv6::ipv6_to_epid12(&ip.to_string()),
+    }
+}
+
+pub fn epid_to_addr(epid: &str) -> Option<String> {
+    let parsed: Epid = epid.parse().ok()?;
+    match parsed.word_count() {
+        3 => ipv4::epid3_to_ipv4(epid),
+        6 => ipv4::epid_to_socketaddr(epid),
+        12 => ipv6::epid12_to_ipv6(epid),
+        _ => None,
+    }
+}
+
+/// Reports the raw integer ordinal an IP/socket address or EPID maps to (the same value
+/// `construct` produces internally), as `<hex> / <decimal> / <address space>`, e.g.
+/// `127.0.0.1` -> `7F000001 / 2130706433 / ipv4`.
+pub fn dump(input: &str) -> Option<String> {
+    let (ordinal, family) = ordinal_and_family(input)?;
+    Some(format!("{:X} / {} / {}", ordinal, ordinal, family))
+}
+
+fn ordinal_and_family(input: &str) -> Option<(Ordinal, &'static str)> {
+    if let Ok(addr) = input.parse::<Address>() {
+        return match addr {
+            Address::V4(ip, None) => ipv4::ipv4_ordinal(&ip.to_string()).map(|o| (o, "ipv4")),
+            Address::V4(ip, Some(port)) =>
+                ipv4::socket_ordinal(&format!("{}:{}", ip, port)).map(|o| (o, "ipv4+port")),
+            Address::V6(ip) => ipv6::ipv6_ordinal(&ip.to_string()).map(|o| (o, "ipv6")),
+        };
+    }
+
+    let epid: Epid = input.parse().ok()?;
+    match epid.word_count() {
+        3 => ipv4::epid3_ordinal(input).map(|o| (o, "ipv4")),
+        6 => ipv4::epid_socket_ordinal(input).map(|o| (o, "ipv4+port")),
+        12 => ipv6::epid12_ordinal(input).map(|o| (o, "ipv6")),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addr_to_epid_accepts_plain_ipv4() {
+        assert_eq!(addr_to_epid("127.0.0.1"), ipv4::ipv4_to_epid3("127.0.0.1"));
+    }
+
+    #[test]
+    fn addr_to_epid_accepts_ipv4_socket() {
+        assert_eq!(addr_to_epid("127.0.0.1:80"), ipv4::socketaddr_to_epid("127.0.0.1:80"));
+    }
+
+    #[test]
+    fn addr_to_epid_accepts_bracketed_ipv6() {
+        assert_eq!(addr_to_epid("[::1]"), ipv6::ipv6_to_epid12("::1"));
+    }
+
+    #[test]
+    fn addr_to_epid_accepts_bare_ipv6() {
+        assert_eq!(addr_to_epid("::ffff:127.0.0.1"), ipv6::ipv6_to_epid12("::ffff:127.0.0.1"));
+    }
+
+    #[test]
+    fn addr_to_epid_rejects_garbage() {
+        assert!(addr_to_epid("not an address").is_none());
+    }
+
+    #[test]
+    fn epid_to_addr_round_trips_every_width() {
+        let epid3 = ipv4::ipv4_to_epid3("127.0.0.1").unwrap();
+        assert_eq!(epid_to_addr(&epid3), ipv4::epid3_to_ipv4(&epid3));
+
+        let epid_socket = ipv4::socketaddr_to_epid("127.0.0.1:80").unwrap();
+        assert_eq!(epid_to_addr(&epid_socket), ipv4::epid_to_socketaddr(&epid_socket));
+
+        let epid12 = ipv6::ipv6_to_epid12("::1").unwrap();
+        assert_eq!(epid_to_addr(&epid12), ipv6::epid12_to_ipv6(&epid12));
+    }
+
+    #[test]
+    fn dump_reports_hex_decimal_and_family_for_ipv4() {
+        assert_eq!(dump("127.0.0.1"), Some("7F000001 / 2130706433 / ipv4".into()));
+    }
+
+    #[test]
+    fn dump_agrees_between_address_and_epid_forms() {
+        let epid3 = ipv4::ipv4_to_epid3("127.0.0.1").unwrap();
+        assert_eq!(dump("127.0.0.1"), dump(&epid3));
+    }
+
+    #[test]
+    fn dump_reports_ipv6_and_socket_families() {
+        assert!(dump("::1").unwrap().ends_with("/ ipv6"));
+        assert!(dump("127.0.0.1:80").unwrap().ends_with("/ ipv4+port"));
+    }
+
+    #[test]
+    fn dump_rejects_garbage() {
+        assert!(dump("not an address").is_none());
+    }
+}
@@ -0,0 +1,98 @@
+// Shared positional-numeral-system helpers used by every address family (IPv4, IPv6, ...)
+// to go back and forth between a single integer ordinal and a fixed-width sequence of word
+// indices. Kept generic over `u128` so the same logic covers both the 32-bit IPv4 range and
+// the full 128-bit IPv6 range without losing precision.
+
+pub(crate) type Ordinal = u128;
+
+pub(crate) fn construct(components: &[Ordinal], base: Ordinal) -> Ordinal {
+    components.iter().rev()
+        .enumerate()
+        .map(|(i, comp)| comp * base.pow(i as u32))
+        .sum()
+}
+
+pub(crate) fn deconstruct(ordinal: Ordinal, len: usize, base: Ordinal) -> Vec<Ordinal> {
+    let mut comp = vec![0; len];
+    let mut rem = ordinal;
+
+    for place in 0..len {
+        comp[len - place - 1] = rem % base;
+        rem /= base;
+        if rem == 0 {
+            break;
+        }
+    }
+    comp
+}
+
+// Smallest `base` such that `base^len` covers all `2^bits` possible ordinals, i.e. the
+// integer analogue of `ceil(2^bits ^ (1/len))`. Found by binary search instead of
+// `(2f32).powf(..)` so it stays exact even when `2^bits` itself overflows a `u128`
+// (the full IPv6 address space is `2^128`, one past `u128::MAX`).
+pub(crate) fn components_base(len: usize, bits: u32) -> Ordinal {
+    let covers_range = |base: Ordinal| match base.checked_pow(len as u32) {
+        // `base^len` overflowed a `u128`, i.e. it's `>= 2^128`, which covers any `bits <= 128`.
+        None => true,
+        // A value that fits in a `u128` is `< 2^128`, so it can never cover the full
+        // 128-bit range no matter how large it is.
+        Some(_) if bits >= 128 => false,
+        Some(value) => value >= (1u128 << bits),
+    };
+
+    let mut lo: Ordinal = 1;
+    let mut hi: Ordinal = 2;
+    while !covers_range(hi) {
+        hi *= 2;
+    }
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if covers_range(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_base_matches_ipv4_precedent() {
+        // (256^4)^(1/3) is between 1625 and 1626, so 4 octets packed into 3 words need
+        // base 1626 per word; this used to be computed with `f32::powf` and is now exact.
+        assert_eq!(components_base(3, 32), 1626);
+        assert_eq!(components_base(4, 32), 256);
+    }
+
+    #[test]
+    fn components_base_covers_full_ipv6_range() {
+        let base = components_base(12, 128);
+        // `base^12` must overflow a `u128`, i.e. actually be `>= 2^128`, for the full
+        // IPv6 address range to round-trip; a `base` of 1 (the old bug) fails this.
+        assert!(base.checked_pow(12).is_none());
+        assert!((base - 1).checked_pow(12).is_some(), "base should be the smallest that covers the range");
+
+        // one fewer word must not be enough to cover the full 128-bit range
+        let smaller = components_base(11, 128);
+        assert!(smaller > base);
+    }
+
+    #[test]
+    fn construct_deconstruct_inverse() {
+        use rand::{thread_rng, Rng};
+        let mut rng = thread_rng();
+
+        for i in 1..100 {
+            let ordinal: Ordinal = rng.gen::<u64>() as Ordinal;
+            let len = i % 10 + 3;
+            let base = components_base(len, 64);
+            let comps = deconstruct(ordinal, len, base);
+            let new_ord = construct(comps.as_slice(), base);
+            assert_eq!(ordinal, new_ord);
+        }
+    }
+}
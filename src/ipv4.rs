@@ -3,6 +3,10 @@
 
 // As (256^4)^(1/3) is between 1625 and 1626, we will select 1626 as the maximum word index possible.
 
+use std::net::Ipv4Addr;
+
+use crate::codec::{self, Ordinal};
+use crate::parser::{self, Parser};
 use crate::wordlist::WORDS;
 
 pub fn epid3_to_ipv4(epid: &str) -> Option<String> {
@@ -15,91 +19,127 @@ pub fn epid3_to_ipv4(epid: &str) -> Option<String> {
 pub fn ipv4_to_epid3(ipv4: &str) -> Option<String> {
     parse_ipv4(ipv4)
         .map(|comps| construct(comps.iter()
-            .map(|it| *it as u32)
-            .collect::<Vec<u32>>().as_slice()))
+            .map(|it| *it as Ordinal)
+            .collect::<Vec<Ordinal>>().as_slice()))
         .map(|ordinal| deconstruct(ordinal, 3))
         .map(|comps| format_epid3(comps.as_slice()))
 }
 
-type OrdinalIPv4 = u32;
-const IPV4_COMBS: usize = 4294967296; // 256^4
+/// The raw integer ordinal `construct`/`deconstruct` map an IPv4 address to; reported
+/// outside the crate through `addr::dump`.
+pub(crate) fn ipv4_ordinal(ipv4: &str) -> Option<Ordinal> {
+    parse_ipv4(ipv4).map(|comps| construct(comps.iter()
+        .map(|it| *it as Ordinal)
+        .collect::<Vec<Ordinal>>().as_slice()))
+}
 
-fn deconstruct(ordinal: OrdinalIPv4, len: usize) -> Vec<OrdinalIPv4> {
-    let mut comp = vec![0; len];
-    let mut rem = ordinal;
-    let base = components_base(len);
+pub(crate) fn epid3_ordinal(epid: &str) -> Option<Ordinal> {
+    parse_epid3(epid).map(|comps| construct(&comps))
+}
 
-    for place in 0..len {
-        comp[len - place - 1] = rem % base;
-        rem /= base;
-        if rem == 0 {
-            break;
-        }
-    }
-    comp
+pub(crate) fn socket_ordinal(socket: &str) -> Option<Ordinal> {
+    parse_ipv4_socket(socket).map(|(ip, port)| combine_ip_port(ip, port))
+}
+
+pub(crate) fn epid_socket_ordinal(epid: &str) -> Option<Ordinal> {
+    parse_epid_socket(epid).map(|comps| codec::construct(&comps, socket_base()))
 }
 
-fn construct(components: &[OrdinalIPv4]) -> OrdinalIPv4 {
-    let base = components_base(components.len());
+const IPV4_BITS: u32 = 32;
+
+fn deconstruct(ordinal: Ordinal, len: usize) -> Vec<Ordinal> {
+    codec::deconstruct(ordinal, len, components_base(len))
+}
 
-    components.iter().rev()
-        .enumerate()
-        .map(|(i, comp)| comp * base.pow(i as u32))
-        .sum()
+fn construct(components: &[Ordinal]) -> Ordinal {
+    codec::construct(components, components_base(components.len()))
 }
 
-fn components_base(len: usize) -> u32 {
-    (IPV4_COMBS as f32).powf(1f32 / (len as f32)).ceil() as u32
+fn components_base(len: usize) -> Ordinal {
+    codec::components_base(len, IPV4_BITS)
 }
 
 // FIXME need more specific errors?
 fn parse_ipv4(ipv4: &str) -> Option<[u8; 4]> {
-    let mut output: [u8; 4] = [0; 4];
-    let comps: Vec<(usize, &str)> = ipv4.split(".").enumerate().collect();
-    
-    if comps.len() != 4 {
-        return None;
-    }
-
-    for (i, comp) in comps {
-        let result = comp.parse::<u8>();
-        match result {
-            Ok(component) => output[i] = component,
-            Err(_) => return None
-        }
-    }
-
-    Some(output)
+    Parser::new(ipv4)
+        .read_till_eof(parser::read_ipv4_addr)
+        .map(|addr| addr.octets())
 }
 
-fn parse_epid3(epid: &str) -> Option<[u32; 3]> {
-    let mut out = [0u32; 3];
-    let comps: Vec<(usize, &str)> = epid.split(".")
-        .enumerate()
-        .collect();
-
-    if comps.len() != 3 {
-        return None
-    }
-
-    for (i, comp) in comps {
-        match WORDS.binary_search(&comp) {
-            Ok(index) => out[i] = index as u32,
-            Err(_) => return None
-        }
-    }
-    
-    Some(out)
+fn parse_epid3(epid: &str) -> Option<[Ordinal; 3]> {
+    let mut words = [0usize; 3];
+    Parser::new(epid)
+        .read_till_eof(|p| parser::read_word_group(p, &mut words))
+        .map(|()| [words[0] as Ordinal, words[1] as Ordinal, words[2] as Ordinal])
 }
 
-fn format_ipv4(components: &[u32]) -> String {
+fn format_ipv4(components: &[Ordinal]) -> String {
     components.iter()
         .map(|comp| comp.to_string())
         .collect::<Vec<String>>()
         .join(".")
 }
 
-fn format_epid3(components: &[u32]) -> String {
+fn format_epid3(components: &[Ordinal]) -> String {
+    components.iter()
+        .map(|i| WORDS[*i as usize])
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+// An IPv4 socket address (ip:port) packs its 16-bit port in as low-order digits of the
+// same ordinal, 48 bits total, which needs 6 words at 8 bits/word (256^6 == 2^48 exactly).
+const SOCKET_WORDS: usize = 6;
+const SOCKET_BITS: u32 = 48;
+
+pub fn socketaddr_to_epid(socket: &str) -> Option<String> {
+    parse_ipv4_socket(socket)
+        .map(|(ip, port)| combine_ip_port(ip, port))
+        .map(|ordinal| codec::deconstruct(ordinal, SOCKET_WORDS, socket_base()))
+        .map(|comps| format_epid_socket(comps.as_slice()))
+}
+
+pub fn epid_to_socketaddr(epid: &str) -> Option<String> {
+    parse_epid_socket(epid)
+        .map(|comps| codec::construct(&comps, socket_base()))
+        .map(split_ip_port)
+        .map(|(ip, port)| format!("{}:{}", Ipv4Addr::from(ip), port))
+}
+
+fn socket_base() -> Ordinal {
+    codec::components_base(SOCKET_WORDS, SOCKET_BITS)
+}
+
+fn combine_ip_port(ip: u32, port: u16) -> Ordinal {
+    ((ip as Ordinal) << 16) | port as Ordinal
+}
+
+fn split_ip_port(ordinal: Ordinal) -> (u32, u16) {
+    ((ordinal >> 16) as u32, (ordinal & 0xFFFF) as u16)
+}
+
+fn parse_ipv4_socket(socket: &str) -> Option<(u32, u16)> {
+    Parser::new(socket).read_till_eof(|p| {
+        let ip = parser::read_ipv4_addr(p)?;
+        let port = parser::read_port(p)?;
+        Some((u32::from(ip), port))
+    })
+}
+
+fn parse_epid_socket(epid: &str) -> Option<[Ordinal; SOCKET_WORDS]> {
+    let mut words = [0usize; SOCKET_WORDS];
+    Parser::new(epid)
+        .read_till_eof(|p| parser::read_word_group(p, &mut words))
+        .map(|()| {
+            let mut out = [0 as Ordinal; SOCKET_WORDS];
+            for (i, word) in words.into_iter().enumerate() {
+                out[i] = word as Ordinal;
+            }
+            out
+        })
+}
+
+fn format_epid_socket(components: &[Ordinal]) -> String {
     components.iter()
         .map(|i| WORDS[*i as usize])
         .collect::<Vec<&str>>()
@@ -118,14 +158,14 @@ mod tests {
 
     #[test]
     fn deconstruct_high_number() {
-        let ordinal = 192 * 256u32.pow(3) + 168 * 256u32.pow(2) + 1;
+        let ordinal = 192 * 256u128.pow(3) + 168 * 256u128.pow(2) + 1;
         let components = deconstruct(ordinal, 4);
         assert_eq!(components, &[192, 168, 0, 1]);
     }
 
     #[test]
     fn deconstruct_three_len() {
-        let ordinal = 525 * 1626u32.pow(2) + 231 * 1626 + 23;
+        let ordinal = 525 * 1626u128.pow(2) + 231 * 1626 + 23;
         let components = deconstruct(ordinal, 3);
         assert_eq!(components, &[525, 231, 23]);
     }
@@ -134,14 +174,14 @@ mod tests {
     fn construct_four_len() {
         let comps = [192, 168, 0, 1];
         let ordinal = construct(&comps);
-        assert_eq!(ordinal, 192 * 256u32.pow(3) + 168 * 256u32.pow(2) + 1);
+        assert_eq!(ordinal, 192 * 256u128.pow(3) + 168 * 256u128.pow(2) + 1);
     }
 
     #[test]
     fn construct_three_len() {
         let comps = [552, 131, 9];
         let ordinal = construct(&comps);
-        assert_eq!(ordinal, 552 * 1626u32.pow(2) + 131 * 1626 + 9);
+        assert_eq!(ordinal, 552 * 1626u128.pow(2) + 131 * 1626 + 9);
     }
 
     #[test]
@@ -150,7 +190,7 @@ mod tests {
         let mut rng = thread_rng();
 
         for i in 1..100 {
-            let ordinal: u32 = rng.gen();
+            let ordinal: Ordinal = rng.gen::<u32>() as Ordinal;
             let comps = deconstruct(ordinal, i % 10 + 3);
             let new_ord = construct(comps.as_slice());
             assert_eq!(ordinal, new_ord);
@@ -171,8 +211,8 @@ mod tests {
 
     #[test]
     fn parse_good_epid3() {
-        let vec: Vec<u32> = ["alerts", "baseline", "brazil"].iter()
-            .map(|word| WORDS.binary_search(&word).unwrap() as u32)
+        let vec: Vec<Ordinal> = ["alerts", "baseline", "brazil"].iter()
+            .map(|word| WORDS.binary_search(word).unwrap() as Ordinal)
             .collect();
         assert_eq!(&parse_epid3("alerts.baseline.brazil").unwrap(), vec.as_slice())
     }
@@ -209,7 +249,7 @@ mod tests {
             rng.fill(&mut ipv4);
 
             let ip = format_ipv4(ipv4.iter()
-                .map(|i| *i as u32).collect::<Vec<u32>>().as_slice());
+                .map(|i| *i as Ordinal).collect::<Vec<Ordinal>>().as_slice());
 
             let epid3 = ipv4_to_epid3(&ip).unwrap();
             let new_ip = epid3_to_ipv4(&epid3).unwrap();
@@ -217,4 +257,42 @@ mod tests {
             assert_eq!(ip, new_ip);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn combine_split_ip_port_inverse() {
+        let (ip, port) = split_ip_port(combine_ip_port(0xC0A80001, 8080));
+        assert_eq!((ip, port), (0xC0A80001, 8080));
+    }
+
+    #[test]
+    fn parse_good_ipv4_sockets() {
+        assert_eq!(parse_ipv4_socket("127.0.0.1:80").unwrap(), (0x7F000001, 80));
+    }
+
+    #[test]
+    fn parse_bad_ipv4_sockets() {
+        assert_eq!(parse_ipv4_socket("[::1]:80"), None);
+        assert!(parse_ipv4_socket("127.0.0.1:70000").is_none());
+        assert!(parse_ipv4_socket("127.0.0.1").is_none());
+        assert!(parse_ipv4_socket("not.an.address:80").is_none());
+    }
+
+    #[test]
+    fn test_socketaddr_epid_inverse() {
+        use rand::{thread_rng, Rng};
+        let mut rng = thread_rng();
+
+        for _ in 0..300 {
+            let mut ipv4 = [0u8; 4];
+            rng.fill(&mut ipv4);
+            let port: u16 = rng.gen();
+
+            let socket = format!("{}.{}.{}.{}:{}", ipv4[0], ipv4[1], ipv4[2], ipv4[3], port);
+
+            let epid = socketaddr_to_epid(&socket).unwrap();
+            let new_socket = epid_to_socketaddr(&epid).unwrap();
+
+            assert_eq!(socket, new_socket);
+        }
+    }
+}
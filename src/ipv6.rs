@@ -0,0 +1,112 @@
+// We can represent an IPv6 address using one EPID12.
+// 128 bits split across 12 words works out to the same ~10.67 bits per word that EPID3
+// already spends on IPv4's 32 bits, so we reuse the same per-word budget, just with more
+// words to cover the much larger address space.
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use crate::codec::{self, Ordinal};
+use crate::parser::{self, Parser};
+use crate::wordlist::WORDS;
+
+const IPV6_WORDS: usize = 12;
+const IPV6_BITS: u32 = 128;
+
+/// The raw integer ordinal `construct`/`deconstruct` map an IPv6 address to; reported
+/// outside the crate through `addr::dump`.
+pub(crate) fn ipv6_ordinal(ipv6: &str) -> Option<Ordinal> {
+    Ipv6Addr::from_str(ipv6).ok().map(|addr| addr.to_bits())
+}
+
+pub(crate) fn epid12_ordinal(epid: &str) -> Option<Ordinal> {
+    parse_epid12(epid).map(|comps| construct(&comps))
+}
+
+pub fn epid12_to_ipv6(epid: &str) -> Option<String> {
+    parse_epid12(epid)
+        .map(|comps| construct(&comps))
+        .map(Ipv6Addr::from_bits)
+        .map(|addr| addr.to_string())
+}
+
+pub fn ipv6_to_epid12(ipv6: &str) -> Option<String> {
+    Ipv6Addr::from_str(ipv6).ok()
+        .map(|addr| deconstruct(addr.to_bits()))
+        .map(|comps| format_epid12(comps.as_slice()))
+}
+
+fn deconstruct(ordinal: Ordinal) -> Vec<Ordinal> {
+    codec::deconstruct(ordinal, IPV6_WORDS, components_base())
+}
+
+fn construct(components: &[Ordinal]) -> Ordinal {
+    codec::construct(components, components_base())
+}
+
+fn components_base() -> Ordinal {
+    codec::components_base(IPV6_WORDS, IPV6_BITS)
+}
+
+fn parse_epid12(epid: &str) -> Option<[Ordinal; IPV6_WORDS]> {
+    let mut words = [0usize; IPV6_WORDS];
+    Parser::new(epid)
+        .read_till_eof(|p| parser::read_word_group(p, &mut words))
+        .map(|()| {
+            let mut out = [0 as Ordinal; IPV6_WORDS];
+            for (i, word) in words.into_iter().enumerate() {
+                out[i] = word as Ordinal;
+            }
+            out
+        })
+}
+
+fn format_epid12(components: &[Ordinal]) -> String {
+    components.iter()
+        .map(|i| WORDS[*i as usize])
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deconstruct_construct_zero() {
+        let components = deconstruct(0);
+        assert_eq!(components, vec![0; IPV6_WORDS]);
+        assert_eq!(construct(&components), 0);
+    }
+
+    #[test]
+    fn deconstruct_construct_max() {
+        let components = deconstruct(u128::MAX);
+        assert_eq!(construct(&components), u128::MAX);
+    }
+
+    #[test]
+    fn parse_bad_epid12s() {
+        let bads = ["too.few.words", "", "UPPER.CASE.WORDS.ARE.NOT.KNOWN.WORDS.HERE.TOO.MANY.NO.WAIT"];
+
+        for bad in bads.iter() {
+            assert!(parse_epid12(bad).is_none());
+        }
+    }
+
+    #[test]
+    fn test_epid12_ipv6_inverse() {
+        use rand::{thread_rng, Rng};
+        let mut rng = thread_rng();
+
+        for _ in 0..300 {
+            let ordinal: u128 = rng.gen();
+            let ip = Ipv6Addr::from_bits(ordinal).to_string();
+
+            let epid12 = ipv6_to_epid12(&ip).unwrap();
+            let new_ip = epid12_to_ipv6(&epid12).unwrap();
+
+            assert_eq!(ip, new_ip);
+        }
+    }
+}
@@ -4,7 +4,12 @@ use wordlist::WORDS;
 extern crate rand;
 use crate::rand::{thread_rng, Rng, distributions::Uniform};
 
+pub(crate) mod codec;
+pub(crate) mod parser;
 pub mod ipv4;
+pub mod ipv6;
+pub mod addr;
+pub mod types;
 
 pub const DIVIDER: &str = ".";
 
@@ -15,8 +15,15 @@ fn main() {
         match tokens[0] {
             "ip" => println!("{}", ipv4::ipv4_to_epid3(tokens[1]).unwrap_or("<bad IP>".into())),
             "epid" => println!("{}", ipv4::epid3_to_ipv4(tokens[1]).unwrap_or("<bad EPID3>".into())),
+            "ip6" => println!("{}", ipv6::ipv6_to_epid12(tokens[1]).unwrap_or("<bad IPv6>".into())),
+            "epid6" => println!("{}", ipv6::epid12_to_ipv6(tokens[1]).unwrap_or("<bad EPID12>".into())),
+            "ipport" => println!("{}", ipv4::socketaddr_to_epid(tokens[1]).unwrap_or("<bad socket address>".into())),
+            "epidport" => println!("{}", ipv4::epid_to_socketaddr(tokens[1]).unwrap_or("<bad EPID6>".into())),
+            "addr" => println!("{}", addr::addr_to_epid(tokens[1]).unwrap_or("<unrecognized address>".into())),
+            "decode" => println!("{}", addr::epid_to_addr(tokens[1]).unwrap_or("<unrecognized EPID>".into())),
+            "dump" | "hex" => println!("{}", addr::dump(tokens[1]).unwrap_or("<unrecognized address or EPID>".into())),
             "quit" => break,
-            _ => println!("Unknown command. Try ip or epid.")
+            _ => println!("Unknown command. Try ip, epid, ip6, epid6, ipport, epidport, addr, decode, dump, or hex.")
         }
         
         line.clear();
@@ -0,0 +1,224 @@
+// A small backtracking combinator parser for address literals. Hand-rolled recursive
+// descent (the old `parse_ipv4`/`parse_epid3`) hard-fails on the first unexpected byte and
+// can't easily express "try this shape, and if it doesn't fit, try that one instead" without
+// duplicating work. `Parser` fixes that: `read_atomically` only commits a sub-parser's
+// position advance if it succeeds, so callers can freely try and discard alternatives.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::wordlist::WORDS;
+
+pub(crate) struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+/// A single alternative passed to `read_or`. Named so the trait-object type doesn't have to
+/// be spelled out (and re-triggers clippy's `type_complexity` lint) at every call site.
+pub(crate) type SubParser<'a, T> = dyn Fn(&mut Parser<'a>) -> Option<T>;
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(s: &'a str) -> Parser<'a> {
+        Parser { s: s.as_bytes(), pos: 0 }
+    }
+
+    /// Runs `cb`, rewinding back to the starting position if it returns `None`.
+    pub(crate) fn read_atomically<T, F>(&mut self, cb: F) -> Option<T>
+    where F: FnOnce(&mut Parser<'a>) -> Option<T> {
+        let start = self.pos;
+        let result = cb(self);
+        if result.is_none() {
+            self.pos = start;
+        }
+        result
+    }
+
+    /// Tries each parser in turn, atomically, returning the first success.
+    pub(crate) fn read_or<T>(&mut self, parsers: &[&SubParser<'a, T>]) -> Option<T> {
+        for parser in parsers {
+            if let Some(result) = self.read_atomically(|p| parser(p)) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// Runs `cb` and only commits if doing so consumes every remaining byte.
+    pub(crate) fn read_till_eof<T, F>(&mut self, cb: F) -> Option<T>
+    where F: FnOnce(&mut Parser<'a>) -> Option<T> {
+        self.read_atomically(|p| {
+            let result = cb(p);
+            if p.pos == p.s.len() { result } else { None }
+        })
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.s.get(self.pos).map(|&b| b as char)
+    }
+
+    fn read_given_char(&mut self, c: char) -> Option<()> {
+        self.read_atomically(|p| {
+            if p.peek_char() == Some(c) {
+                p.pos += 1;
+                Some(())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Reads up to `max_digits` base-`radix` digits, rejecting results over `max_value`.
+    fn read_number(&mut self, radix: u32, max_digits: usize, max_value: u32) -> Option<u32> {
+        self.read_atomically(|p| {
+            let mut result: u32 = 0;
+            let mut digits = 0;
+
+            while digits < max_digits {
+                match p.peek_char().and_then(|c| c.to_digit(radix)) {
+                    Some(digit) => {
+                        result = result * radix + digit;
+                        if result > max_value {
+                            return None;
+                        }
+                        p.pos += 1;
+                        digits += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if digits == 0 { None } else { Some(result) }
+        })
+    }
+
+    /// Reads a run of lowercase ASCII letters, the alphabet every entry in `WORDS` is drawn from.
+    fn read_word_text(&mut self) -> Option<&'a str> {
+        self.read_atomically(|p| {
+            let start = p.pos;
+            while p.peek_char().is_some_and(|c| c.is_ascii_lowercase()) {
+                p.pos += 1;
+            }
+            if p.pos == start {
+                return None;
+            }
+            std::str::from_utf8(&p.s[start..p.pos]).ok()
+        })
+    }
+}
+
+pub(crate) fn read_ipv4_addr(p: &mut Parser) -> Option<Ipv4Addr> {
+    p.read_atomically(|p| {
+        let a = p.read_number(10, 3, 255)? as u8;
+        p.read_given_char('.')?;
+        let b = p.read_number(10, 3, 255)? as u8;
+        p.read_given_char('.')?;
+        let c = p.read_number(10, 3, 255)? as u8;
+        p.read_given_char('.')?;
+        let d = p.read_number(10, 3, 255)? as u8;
+        Some(Ipv4Addr::new(a, b, c, d))
+    })
+}
+
+/// IPv6's `::` zero-run compression and embedded IPv4 tail (`::ffff:127.0.0.1`) are already
+/// handled correctly by `Ipv6Addr::from_str`, so we scan out the run of characters an IPv6
+/// literal can contain and hand it to the standard parser rather than reimplementing it.
+pub(crate) fn read_ipv6_addr(p: &mut Parser) -> Option<Ipv6Addr> {
+    p.read_atomically(|p| {
+        let start = p.pos;
+        while p.peek_char().is_some_and(|c| c.is_ascii_hexdigit() || c == ':' || c == '.') {
+            p.pos += 1;
+        }
+        let text = std::str::from_utf8(&p.s[start..p.pos]).ok()?;
+        text.parse::<Ipv6Addr>().ok()
+    })
+}
+
+pub(crate) fn read_bracketed_ipv6_addr(p: &mut Parser) -> Option<Ipv6Addr> {
+    p.read_atomically(|p| {
+        p.read_given_char('[')?;
+        let addr = read_ipv6_addr(p)?;
+        p.read_given_char(']')?;
+        Some(addr)
+    })
+}
+
+pub(crate) fn read_port(p: &mut Parser) -> Option<u16> {
+    p.read_atomically(|p| {
+        p.read_given_char(':')?;
+        p.read_number(10, 5, u16::MAX as u32).map(|n| n as u16)
+    })
+}
+
+/// Reads one dictionary word and returns its index into `WORDS`.
+pub(crate) fn read_word(p: &mut Parser) -> Option<usize> {
+    p.read_atomically(|p| WORDS.binary_search(&p.read_word_text()?).ok())
+}
+
+/// Reads exactly `out.len()` dot-separated dictionary words directly into `out`, without
+/// allocating an intermediate `Vec`.
+pub(crate) fn read_word_group(p: &mut Parser, out: &mut [usize]) -> Option<()> {
+    p.read_atomically(|p| {
+        for (i, slot) in out.iter_mut().enumerate() {
+            if i > 0 {
+                p.read_given_char('.')?;
+            }
+            *slot = read_word(p)?;
+        }
+        Some(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_atomically_rewinds_on_failure() {
+        let mut p = Parser::new("abc");
+        let result = p.read_atomically(|p| {
+            p.pos += 1;
+            None::<()>
+        });
+        assert_eq!(result, None);
+        assert_eq!(p.pos, 0);
+    }
+
+    #[test]
+    fn read_ipv4_addr_parses_dotted_quad() {
+        let mut p = Parser::new("192.168.1.1");
+        assert_eq!(p.read_till_eof(read_ipv4_addr), Some(Ipv4Addr::new(192, 168, 1, 1)));
+    }
+
+    #[test]
+    fn read_ipv6_addr_handles_compression_and_embedded_ipv4() {
+        let mut p = Parser::new("::ffff:127.0.0.1");
+        assert_eq!(p.read_till_eof(read_ipv6_addr), "::ffff:127.0.0.1".parse().ok());
+    }
+
+    #[test]
+    fn read_bracketed_ipv6_addr_strips_brackets() {
+        let mut p = Parser::new("[::1]");
+        assert_eq!(p.read_till_eof(read_bracketed_ipv6_addr), Some(Ipv6Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn read_port_rejects_out_of_range() {
+        let mut p = Parser::new(":70000");
+        assert_eq!(read_port(&mut p), None);
+    }
+
+    #[test]
+    fn read_or_tries_alternatives_in_order() {
+        // Consumes the address before failing on the missing port, so `read_or` must
+        // rewind before trying the next alternative.
+        fn read_ipv4_with_port(p: &mut Parser) -> Option<Ipv4Addr> {
+            let ip = read_ipv4_addr(p)?;
+            read_port(p)?;
+            Some(ip)
+        }
+
+        let mut p = Parser::new("192.168.1.1");
+        let parsers: Vec<&SubParser<Ipv4Addr>> = vec![&read_ipv4_with_port, &read_ipv4_addr];
+        assert_eq!(p.read_or(&parsers), Some(Ipv4Addr::new(192, 168, 1, 1)));
+    }
+}
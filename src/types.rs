@@ -0,0 +1,201 @@
+// Idiomatic, typed front door for the crate. The free functions in `ipv4`/`ipv6`/`addr`
+// remain the `Option<String>` primitives; `Epid` and `Address` wrap them behind
+// `FromStr`/`Display` (and proper error types) for callers who'd rather write
+// `"strong.curious.dolphin".parse::<Epid>()?` than thread strings and `Option`s by hand.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::parser::{self, Parser, SubParser};
+use crate::wordlist::WORDS;
+use crate::{ipv4, DIVIDER};
+
+/// A parsed EPID: the dictionary word indices it decodes to. The word count determines
+/// which address family it represents (3 words = IPv4, 6 = IPv4 socket, 12 = IPv6).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Epid {
+    words: Vec<usize>,
+}
+
+impl Epid {
+    pub fn word_count(&self) -> usize {
+        self.words.len()
+    }
+}
+
+impl fmt::Display for Epid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let joined = self.words.iter()
+            .map(|i| WORDS[*i])
+            .collect::<Vec<&str>>()
+            .join(DIVIDER);
+        write!(f, "{}", joined)
+    }
+}
+
+impl FromStr for Epid {
+    type Err = EpidParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split(DIVIDER)
+            .map(|word| WORDS.binary_search(&word).map_err(|_| EpidParseError::UnknownWord(word.to_string())))
+            .collect::<Result<Vec<usize>, EpidParseError>>()?;
+
+        match words.len() {
+            3 | 6 | 12 => Ok(Epid { words }),
+            other => Err(EpidParseError::WrongWordCount(other)),
+        }
+    }
+}
+
+/// Why a string failed to parse as an [`Epid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EpidParseError {
+    UnknownWord(String),
+    WrongWordCount(usize),
+}
+
+impl fmt::Display for EpidParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpidParseError::UnknownWord(word) => write!(f, "'{}' is not a word in the EPID dictionary", word),
+            EpidParseError::WrongWordCount(count) =>
+                write!(f, "EPID has {} words, expected 3 (IPv4), 6 (IPv4 socket), or 12 (IPv6)", count),
+        }
+    }
+}
+
+impl Error for EpidParseError {}
+
+impl TryFrom<Ipv4Addr> for Epid {
+    type Error = EpidParseError;
+
+    fn try_from(addr: Ipv4Addr) -> Result<Self, Self::Error> {
+        ipv4::ipv4_to_epid3(&addr.to_string())
+            .expect("encoding a valid Ipv4Addr into an EPID3 always succeeds")
+            .parse()
+    }
+}
+
+impl From<Epid> for Option<Ipv4Addr> {
+    fn from(epid: Epid) -> Self {
+        ipv4::epid3_to_ipv4(&epid.to_string())?.parse().ok()
+    }
+}
+
+/// A textual address of any family/shape this crate understands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    V4(Ipv4Addr, Option<u16>),
+    V6(Ipv6Addr),
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Address::V4(ip, Some(port)) => write!(f, "{}:{}", ip, port),
+            Address::V4(ip, None) => write!(f, "{}", ip),
+            Address::V6(ip) => write!(f, "{}", ip),
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = AddressParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s)
+            .read_till_eof(|p| p.read_or(&[
+                &read_v4_socket as &SubParser<Address>,
+                &read_v4,
+                &read_v6,
+            ]))
+            .ok_or(AddressParseError)
+    }
+}
+
+fn read_v4_socket(p: &mut Parser) -> Option<Address> {
+    let ip = parser::read_ipv4_addr(p)?;
+    let port = parser::read_port(p)?;
+    Some(Address::V4(ip, Some(port)))
+}
+
+fn read_v4(p: &mut Parser) -> Option<Address> {
+    parser::read_ipv4_addr(p).map(|ip| Address::V4(ip, None))
+}
+
+fn read_v6(p: &mut Parser) -> Option<Address> {
+    parser::read_bracketed_ipv6_addr(p)
+        .or_else(|| parser::read_ipv6_addr(p))
+        .map(Address::V6)
+}
+
+/// An address string didn't match any shape this crate understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressParseError;
+
+impl fmt::Display for AddressParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a recognized IPv4, IPv4 socket, or IPv6 address")
+    }
+}
+
+impl Error for AddressParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epid_roundtrips_through_display_and_fromstr() {
+        let epid: Epid = "alerts.baseline.brazil".parse().unwrap();
+        assert_eq!(epid.to_string(), "alerts.baseline.brazil");
+        assert_eq!(epid.word_count(), 3);
+    }
+
+    #[test]
+    fn epid_rejects_unknown_word() {
+        assert_eq!(
+            "palabras.en.espanol".parse::<Epid>(),
+            Err(EpidParseError::UnknownWord("palabras".into()))
+        );
+    }
+
+    #[test]
+    fn epid_rejects_wrong_word_count() {
+        assert_eq!(
+            "make.war".parse::<Epid>(),
+            Err(EpidParseError::WrongWordCount(2))
+        );
+    }
+
+    #[test]
+    fn ipv4_addr_epid_roundtrip() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let epid = Epid::try_from(ip).unwrap();
+        let back: Option<Ipv4Addr> = epid.into();
+        assert_eq!(back, Some(ip));
+    }
+
+    #[test]
+    fn address_parses_every_supported_shape() {
+        assert_eq!("127.0.0.1".parse(), Ok(Address::V4(Ipv4Addr::new(127, 0, 0, 1), None)));
+        assert_eq!("127.0.0.1:80".parse(), Ok(Address::V4(Ipv4Addr::new(127, 0, 0, 1), Some(80))));
+        assert_eq!("[::1]".parse(), Ok(Address::V6(Ipv6Addr::LOCALHOST)));
+        assert_eq!("::1".parse(), Ok(Address::V6(Ipv6Addr::LOCALHOST)));
+    }
+
+    #[test]
+    fn address_display_matches_input_shape() {
+        assert_eq!(Address::V4(Ipv4Addr::new(127, 0, 0, 1), Some(80)).to_string(), "127.0.0.1:80");
+        assert_eq!(Address::V6(Ipv6Addr::LOCALHOST).to_string(), "::1");
+    }
+
+    #[test]
+    fn address_rejects_garbage() {
+        assert_eq!("not an address".parse::<Address>(), Err(AddressParseError));
+    }
+}